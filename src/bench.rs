@@ -0,0 +1,364 @@
+use std::cell::RefCell;
+use std::time::Instant;
+
+use ordered_float::NotNan;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::callback::OptProgress;
+use crate::optim::{
+    EpsilonGreedyOptimizer, HillClimbingOptimizer, LocalSearchOptimizer,
+    LogisticAnnealingOptimizer, RelativeAnnealingOptimizer,
+};
+use crate::{Duration, OptModel};
+
+/// Object-safe view of [`LocalSearchOptimizer`] used to box heterogeneous
+/// optimizers.
+///
+/// [`LocalSearchOptimizer`] has generic methods (`optimize<F>`), so it is not
+/// dyn-compatible. This trait erases the callback to a concrete `&dyn Fn` and is
+/// blanket-implemented for every [`LocalSearchOptimizer`], so
+/// `Box<dyn DynLocalSearchOptimizer>` can hold any of them; other optimizers are
+/// boxed through [`Adapter`].
+pub trait DynLocalSearchOptimizer<M: OptModel> {
+    /// See [`LocalSearchOptimizer::optimize`].
+    fn optimize_dyn(
+        &self,
+        model: &M,
+        initial_solution: M::SolutionType,
+        initial_score: M::ScoreType,
+        n_iter: usize,
+        time_limit: Duration,
+        callback: Option<&dyn Fn(OptProgress<M::SolutionType, M::ScoreType>)>,
+    ) -> (M::SolutionType, M::ScoreType);
+}
+
+impl<M: OptModel, T: LocalSearchOptimizer<M>> DynLocalSearchOptimizer<M> for T {
+    fn optimize_dyn(
+        &self,
+        model: &M,
+        initial_solution: M::SolutionType,
+        initial_score: M::ScoreType,
+        n_iter: usize,
+        time_limit: Duration,
+        callback: Option<&dyn Fn(OptProgress<M::SolutionType, M::ScoreType>)>,
+    ) -> (M::SolutionType, M::ScoreType) {
+        match callback {
+            Some(cb) => {
+                self.optimize(model, initial_solution, initial_score, n_iter, time_limit, Some(&cb))
+            }
+            None => self.optimize::<&dyn Fn(OptProgress<M::SolutionType, M::ScoreType>)>(
+                model,
+                initial_solution,
+                initial_score,
+                n_iter,
+                time_limit,
+                None,
+            ),
+        }
+    }
+}
+
+/// Adapter that boxes an optimizer into [`DynLocalSearchOptimizer`] without it
+/// implementing the [`LocalSearchOptimizer`] trait itself.
+///
+/// The blanket impl above only covers optimizers on the base.rs trait
+/// (`SimulatedAnnealingOptimizer`, `IteratedLocalSearchOptimizer`). The greedy
+/// optimizers expose only an inherent `optimize`, and the annealers on the
+/// `ExtraIn`/`ExtraOut` trait have a different signature, so neither can satisfy
+/// that blanket. Wrapping one in `Adapter` erases its concrete signature to
+/// [`DynLocalSearchOptimizer`] so every family can be compared by [`StudyRunner`].
+pub struct Adapter<O>(pub O);
+
+/// Implements [`DynLocalSearchOptimizer`] for the greedy optimizers, whose
+/// inherent `optimize(model, Option<state>, n_iter, callback)` terminates on
+/// `patience`/`n_iter`; the `time_limit` is therefore ignored.
+///
+/// The greedy optimizers work in `StateType`, so this bridge only applies to
+/// models whose `StateType` and `SolutionType` coincide. The callback is
+/// re-borrowed (`Some(&cb)`) / pinned with a turbofish on the `None` arm so the
+/// inner `optimize`'s generic `F` stays `Sized`.
+macro_rules! impl_dyn_greedy {
+    ($opt:ty) => {
+        impl<M> DynLocalSearchOptimizer<M> for Adapter<$opt>
+        where
+            M: OptModel<StateType = <M as OptModel>::SolutionType> + Sync + Send,
+        {
+            fn optimize_dyn(
+                &self,
+                model: &M,
+                initial_solution: M::SolutionType,
+                _initial_score: M::ScoreType,
+                n_iter: usize,
+                _time_limit: Duration,
+                callback: Option<&dyn Fn(OptProgress<M::SolutionType, M::ScoreType>)>,
+            ) -> (M::SolutionType, M::ScoreType) {
+                match callback {
+                    Some(cb) => self.0.optimize(model, Some(initial_solution), n_iter, Some(&cb)),
+                    None => self
+                        .0
+                        .optimize::<M, &dyn Fn(OptProgress<M::SolutionType, M::ScoreType>)>(
+                            model,
+                            Some(initial_solution),
+                            n_iter,
+                            None,
+                        ),
+                }
+            }
+        }
+    };
+}
+
+impl_dyn_greedy!(HillClimbingOptimizer);
+impl_dyn_greedy!(EpsilonGreedyOptimizer);
+
+/// Implements [`DynLocalSearchOptimizer`] for the annealers on the
+/// `ExtraIn`/`ExtraOut` trait, discarding the (unit) extra output. As in the
+/// greedy macro the callback is re-borrowed / turbofish-pinned so the inner
+/// `optimize`'s generic `F` stays `Sized`.
+macro_rules! impl_dyn_extra {
+    ($opt:ty) => {
+        impl<M: OptModel<ScoreType = NotNan<f64>> + Sync + Send> DynLocalSearchOptimizer<M>
+            for Adapter<$opt>
+        {
+            fn optimize_dyn(
+                &self,
+                model: &M,
+                initial_solution: M::SolutionType,
+                _initial_score: M::ScoreType,
+                n_iter: usize,
+                time_limit: Duration,
+                callback: Option<&dyn Fn(OptProgress<M::SolutionType, M::ScoreType>)>,
+            ) -> (M::SolutionType, M::ScoreType) {
+                let (solution, score, _) = match callback {
+                    Some(cb) => {
+                        self.0
+                            .optimize(model, Some(initial_solution), n_iter, time_limit, Some(&cb), ())
+                    }
+                    None => self
+                        .0
+                        .optimize::<&dyn Fn(OptProgress<M::SolutionType, M::ScoreType>)>(
+                            model,
+                            Some(initial_solution),
+                            n_iter,
+                            time_limit,
+                            None,
+                            (),
+                        ),
+                };
+                (solution, score)
+            }
+        }
+    };
+}
+
+impl_dyn_extra!(RelativeAnnealingOptimizer);
+impl_dyn_extra!(LogisticAnnealingOptimizer);
+
+/// A boxed optimizer together with a human-readable label.
+type NamedOptimizer<M> = (String, Box<dyn DynLocalSearchOptimizer<M> + Sync + Send>);
+
+/// Result of a single `(optimizer, seed)` run.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RunRecord {
+    /// label of the optimizer that produced this run
+    pub optimizer: String,
+    /// RNG seed used to generate the initial solution
+    pub seed: u64,
+    /// wall-clock seconds the run took
+    pub elapsed_secs: f64,
+    /// best score found
+    pub best_score: f64,
+    /// best-score-vs-iteration curve captured through the callback
+    pub score_curve: Vec<f64>,
+}
+
+/// Aggregated statistics over all seeds for a single optimizer.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StudyRecord {
+    /// label of the optimizer
+    pub optimizer: String,
+    /// mean of the per-seed best scores
+    pub mean_score: f64,
+    /// best of the per-seed best scores
+    pub best_score: f64,
+    /// worst of the per-seed best scores
+    pub worst_score: f64,
+    /// mean wall-clock seconds over the runs that reached `target`, if any
+    pub time_to_target: Option<f64>,
+    /// the individual runs that were aggregated
+    pub runs: Vec<RunRecord>,
+}
+
+/// Runs a set of optimizers over a set of RNG seeds and aggregates the results.
+///
+/// Every `(optimizer, seed)` pair is run in parallel with rayon, recording the
+/// elapsed seconds, best score, and best-score-vs-iteration curve captured
+/// through the callback, then aggregated into one [`StudyRecord`] per optimizer.
+pub struct StudyRunner<'a, M: OptModel> {
+    optimizers: Vec<NamedOptimizer<M>>,
+    model: &'a M,
+    seeds: Vec<u64>,
+    n_iter: usize,
+    time_limit: Duration,
+    n_workers: usize,
+    target: Option<f64>,
+}
+
+impl<'a, M> StudyRunner<'a, M>
+where
+    M: OptModel + Sync + Send,
+    M::SolutionType: Send,
+    M::ScoreType: Into<f64> + Copy + Send,
+{
+    /// Constructor of StudyRunner
+    ///
+    /// - `optimizers` : the labelled, boxed optimizers to compare
+    /// - `model` : the model every optimizer is run against
+    /// - `seeds` : RNG seeds; one run per optimizer per seed
+    /// - `n_iter` : maximum iterations passed to each run
+    /// - `time_limit` : time limit passed to each run
+    /// - `n_workers` : number of rayon worker threads
+    /// - `target` : optional score target used for the time-to-target statistic
+    pub fn new(
+        optimizers: Vec<NamedOptimizer<M>>,
+        model: &'a M,
+        seeds: Vec<u64>,
+        n_iter: usize,
+        time_limit: Duration,
+        n_workers: usize,
+        target: Option<f64>,
+    ) -> Self {
+        Self {
+            optimizers,
+            model,
+            seeds,
+            n_iter,
+            time_limit,
+            n_workers,
+            target,
+        }
+    }
+
+    /// Runs every `(optimizer, seed)` pair and returns one [`StudyRecord`] per optimizer.
+    pub fn run(&self) -> anyhow::Result<Vec<StudyRecord>> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.n_workers)
+            .build()?;
+
+        // flatten to the full optimizer x seed product so every pair is one
+        // parallel task, rather than parallelizing seeds within a sequential
+        // walk over optimizers.
+        let pairs = self
+            .optimizers
+            .iter()
+            .enumerate()
+            .flat_map(|(oi, (name, optimizer))| {
+                self.seeds
+                    .iter()
+                    .map(move |&seed| (oi, name.as_str(), optimizer.as_ref(), seed))
+            })
+            .collect::<Vec<_>>();
+
+        let mut runs = pool.install(|| {
+            pairs
+                .par_iter()
+                .map(|&(oi, name, optimizer, seed)| {
+                    self.run_one(name, optimizer, seed).map(|r| (oi, r))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()
+        })?;
+
+        // regroup the flat results back into one record per optimizer, keeping
+        // the input order.
+        runs.sort_by_key(|(oi, _)| *oi);
+        let records = self
+            .optimizers
+            .iter()
+            .enumerate()
+            .map(|(oi, (name, _))| {
+                let runs = runs
+                    .iter()
+                    .filter(|(i, _)| *i == oi)
+                    .map(|(_, r)| r.clone())
+                    .collect::<Vec<_>>();
+                self.aggregate(name, runs)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(records)
+    }
+
+    /// Runs a single optimizer with a seeded initial solution.
+    fn run_one(
+        &self,
+        name: &str,
+        optimizer: &(dyn DynLocalSearchOptimizer<M> + Sync + Send),
+        seed: u64,
+    ) -> anyhow::Result<RunRecord> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let (initial_solution, initial_score) = self.model.generate_random_solution(&mut rng)?;
+
+        let score_curve = RefCell::new(Vec::new());
+        let callback = |progress: OptProgress<M::SolutionType, M::ScoreType>| {
+            score_curve.borrow_mut().push(progress.score.into());
+        };
+
+        let start_time = Instant::now();
+        let (_, best_score) = optimizer.optimize_dyn(
+            self.model,
+            initial_solution,
+            initial_score,
+            self.n_iter,
+            self.time_limit,
+            Some(&callback),
+        );
+        let elapsed_secs = start_time.elapsed().as_secs_f64();
+
+        Ok(RunRecord {
+            optimizer: name.to_string(),
+            seed,
+            elapsed_secs,
+            best_score: best_score.into(),
+            score_curve: score_curve.into_inner(),
+        })
+    }
+
+    /// Aggregates per-seed runs into a single record.
+    fn aggregate(&self, name: &str, runs: Vec<RunRecord>) -> StudyRecord {
+        let n = runs.len() as f64;
+        let mean_score = runs.iter().map(|r| r.best_score).sum::<f64>() / n;
+        let best_score = runs
+            .iter()
+            .map(|r| r.best_score)
+            .fold(f64::INFINITY, f64::min);
+        let worst_score = runs
+            .iter()
+            .map(|r| r.best_score)
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        let time_to_target = self.target.and_then(|target| {
+            let reached = runs
+                .iter()
+                .filter(|r| r.best_score <= target)
+                .map(|r| r.elapsed_secs)
+                .collect::<Vec<_>>();
+            if reached.is_empty() {
+                None
+            } else {
+                Some(reached.iter().sum::<f64>() / reached.len() as f64)
+            }
+        });
+
+        StudyRecord {
+            optimizer: name.to_string(),
+            mean_score,
+            best_score,
+            worst_score,
+            time_to_target,
+            runs,
+        }
+    }
+}
@@ -6,6 +6,7 @@ use rayon::prelude::*;
 use crate::OptModel;
 
 use super::callback::{OptCallbackFn, OptProgress};
+use super::incremental::IncrementalOptModel;
 
 #[derive(Clone, Copy)]
 pub struct EpsilonGreedyOptimizer {
@@ -86,4 +87,81 @@ impl EpsilonGreedyOptimizer {
         let best_state = (*best_state.borrow()).clone();
         (best_state, best_score)
     }
+
+    /// Start optimization using the incremental [`IncrementalOptModel`] path.
+    ///
+    /// Like [`EpsilonGreedyOptimizer::optimize`] but proposes transitions with
+    /// `generate_transition`, scores them with `evaluate_delta`, and mutates a
+    /// single owned state in place: the best transition is applied, then rolled
+    /// back with `rollback_move` when it is neither an improvement nor an
+    /// epsilon-exploration acceptance.
+    ///
+    /// - `model` : the model to optimize
+    /// - `initial_state` : the initial state to start optimization. If None, a random state will be generated.
+    /// - `n_iter`: maximum iterations
+    /// - `callback` : callback function that will be invoked at the end of each iteration
+    pub fn optimize_incremental<M, F>(
+        &self,
+        model: &M,
+        initial_state: Option<M::StateType>,
+        n_iter: usize,
+        callback: Option<&F>,
+    ) -> (M::StateType, M::ScoreType)
+    where
+        M: IncrementalOptModel,
+        M::ScoreType: std::ops::Add<Output = M::ScoreType>,
+        F: OptCallbackFn<M::StateType, M::ScoreType>,
+    {
+        let mut rng = rand::thread_rng();
+        let mut current_state = if let Some(s) = initial_state {
+            s
+        } else {
+            model.generate_random_state(&mut rng).unwrap()
+        };
+        let mut current_score = model.evaluate_state(&current_state);
+        let best_state = Rc::new(RefCell::new(current_state.clone()));
+        let mut best_score = current_score;
+        let mut counter = 0;
+        let mut accepted_counter = 0;
+        for it in 0..n_iter {
+            let (transition, trial_score) = (0..self.n_trials)
+                .map(|_| {
+                    let transition = model.generate_transition(&current_state, &mut rng);
+                    let trial_score =
+                        current_score + model.evaluate_delta(&current_state, &transition);
+                    (transition, trial_score)
+                })
+                .min_by_key(|(_, score)| *score)
+                .unwrap();
+
+            let r: f64 = rng.gen();
+            model.apply_move(&mut current_state, &transition);
+            if trial_score < current_score || self.epsilon > r {
+                current_score = trial_score;
+                accepted_counter += 1;
+            } else {
+                model.rollback_move(&mut current_state, &transition);
+            }
+
+            if current_score < best_score {
+                best_state.replace(current_state.clone());
+                best_score = current_score;
+                counter = 0;
+            }
+
+            counter += 1;
+            if counter == self.patience {
+                break;
+            }
+
+            if let Some(f) = callback {
+                let progress =
+                    OptProgress::new(it, accepted_counter, best_state.clone(), best_score);
+                f(progress);
+            }
+        }
+
+        let best_state = (*best_state.borrow()).clone();
+        (best_state, best_score)
+    }
 }
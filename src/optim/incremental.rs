@@ -0,0 +1,185 @@
+use rand::Rng;
+
+use crate::OptModel;
+
+/// Opt-in incremental scoring path for [`OptModel`].
+///
+/// The default optimizers clone the whole state per trial and re-evaluate from
+/// scratch, which dominates runtime on large problems. A model that can propose
+/// a transition without building a state, score its *delta* in O(1), and apply /
+/// roll it back in place implements this trait so the optimizers can mutate a
+/// single owned state instead of cloning.
+///
+/// Implementing this trait is optional; models that do not keep working through
+/// the clone-based path unchanged. The incremental path is provided for the
+/// greedy optimizers ([`HillClimbingOptimizer`](super::HillClimbingOptimizer) and
+/// [`EpsilonGreedyOptimizer`](super::EpsilonGreedyOptimizer)) and for the
+/// annealer ([`SimulatedAnnealingOptimizer`](super::SimulatedAnnealingOptimizer)),
+/// whose per-trial clone is the dominant cost on large problems.
+pub trait IncrementalOptModel: OptModel {
+    /// Proposes a transition from `state` without materializing a trial state.
+    fn generate_transition<R: Rng>(
+        &self,
+        state: &Self::StateType,
+        rng: &mut R,
+    ) -> Self::TransitionType;
+
+    /// Score change produced by `transition`, such that the score after applying
+    /// it is `current_score + evaluate_delta(state, transition)`.
+    fn evaluate_delta(
+        &self,
+        state: &Self::StateType,
+        transition: &Self::TransitionType,
+    ) -> Self::ScoreType;
+
+    /// Applies `transition` to `state` in place.
+    fn apply_move(&self, state: &mut Self::StateType, transition: &Self::TransitionType);
+
+    /// Undoes a previously applied `transition`, restoring `state`.
+    fn rollback_move(&self, state: &mut Self::StateType, transition: &Self::TransitionType);
+}
+
+#[cfg(test)]
+mod test {
+    use super::IncrementalOptModel;
+    use crate::optim::HillClimbingOptimizer;
+    use crate::OptModel;
+
+    /// Minimizes the L1 distance of an integer vector to a fixed target over a
+    /// bounded domain. A transition `(index, old, new)` carries the replaced
+    /// value so the move can be rolled back without re-reading the state.
+    struct L1Model {
+        target: Vec<i64>,
+        bound: i64,
+    }
+
+    impl OptModel for L1Model {
+        type ScoreType = i64;
+        type StateType = Vec<i64>;
+        type SolutionType = Vec<i64>;
+        type TransitionType = (usize, i64, i64);
+
+        fn generate_random_state<R: rand::Rng>(&self, rng: &mut R) -> anyhow::Result<Self::StateType> {
+            Ok((0..self.target.len())
+                .map(|_| rng.gen_range(0..=self.bound))
+                .collect())
+        }
+
+        fn generate_random_solution<R: rand::Rng>(
+            &self,
+            rng: &mut R,
+        ) -> anyhow::Result<(Self::SolutionType, Self::ScoreType)> {
+            let state = self.generate_random_state(rng)?;
+            let score = self.evaluate_state(&state);
+            Ok((state, score))
+        }
+
+        fn generate_trial_state<R: rand::Rng>(
+            &self,
+            current_state: &Self::StateType,
+            rng: &mut R,
+            _current_score: Option<Self::ScoreType>,
+        ) -> (Self::StateType, Self::TransitionType, Self::ScoreType) {
+            let transition = self.generate_transition(current_state, rng);
+            let mut state = current_state.clone();
+            self.apply_move(&mut state, &transition);
+            let score = self.evaluate_state(&state);
+            (state, transition, score)
+        }
+
+        fn evaluate_state(&self, state: &Self::StateType) -> Self::ScoreType {
+            state
+                .iter()
+                .zip(self.target.iter())
+                .map(|(x, t)| (x - t).abs())
+                .sum()
+        }
+
+        fn preprocess_solution(
+            &self,
+            solution: Self::SolutionType,
+            score: Self::ScoreType,
+        ) -> anyhow::Result<(Self::SolutionType, Self::ScoreType)> {
+            Ok((solution, score))
+        }
+
+        fn postprocess_solution(
+            &self,
+            solution: Self::SolutionType,
+            score: Self::ScoreType,
+        ) -> (Self::SolutionType, Self::ScoreType) {
+            (solution, score)
+        }
+    }
+
+    impl IncrementalOptModel for L1Model {
+        fn generate_transition<R: rand::Rng>(
+            &self,
+            state: &Self::StateType,
+            rng: &mut R,
+        ) -> Self::TransitionType {
+            let index = rng.gen_range(0..state.len());
+            let new_value = rng.gen_range(0..=self.bound);
+            (index, state[index], new_value)
+        }
+
+        fn evaluate_delta(
+            &self,
+            _state: &Self::StateType,
+            transition: &Self::TransitionType,
+        ) -> Self::ScoreType {
+            let &(index, old, new) = transition;
+            let t = self.target[index];
+            (new - t).abs() - (old - t).abs()
+        }
+
+        fn apply_move(&self, state: &mut Self::StateType, transition: &Self::TransitionType) {
+            let &(index, _, new) = transition;
+            state[index] = new;
+        }
+
+        fn rollback_move(&self, state: &mut Self::StateType, transition: &Self::TransitionType) {
+            let &(index, old, _) = transition;
+            state[index] = old;
+        }
+    }
+
+    #[test]
+    fn apply_then_rollback_restores_state() {
+        let model = L1Model {
+            target: vec![3, -1, 4],
+            bound: 10,
+        };
+        let original = vec![0, 0, 0];
+        let mut state = original.clone();
+        let transition = (1, state[1], 7);
+        model.apply_move(&mut state, &transition);
+        assert_eq!(state[1], 7);
+        // the delta must match a full re-evaluation of the applied state
+        assert_eq!(
+            model.evaluate_delta(&original, &transition),
+            model.evaluate_state(&state) - model.evaluate_state(&original)
+        );
+        model.rollback_move(&mut state, &transition);
+        assert_eq!(state, original);
+    }
+
+    #[test]
+    fn incremental_matches_clone_path() {
+        let model = L1Model {
+            target: vec![3, 0, 4, 1, 2],
+            bound: 10,
+        };
+        let opt = HillClimbingOptimizer::new(10000, 10);
+        let null_closure = None::<&fn(_)>;
+
+        let (_, clone_score) = opt.optimize(&model, None, 50000, null_closure);
+        let (incremental_state, incremental_score) =
+            opt.optimize_incremental(&model, None, 50000, null_closure);
+
+        // both paths reach the global optimum (all coordinates on target)
+        assert_eq!(clone_score, 0);
+        assert_eq!(incremental_score, 0);
+        assert_eq!(incremental_state, model.target);
+    }
+}
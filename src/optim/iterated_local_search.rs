@@ -0,0 +1,138 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Instant;
+
+use rand::Rng;
+
+use crate::{callback::OptCallbackFn, Duration, OptModel};
+
+use super::{LocalSearchOptimizer, TransitionProbabilityFn};
+
+/// Optimizer that implements the iterated local search (ILS) meta-strategy.
+///
+/// It wraps any inner [`LocalSearchOptimizer`]: the inner search is run to a
+/// local optimum, the current restart anchor (initially that first local optimum)
+/// is then "kicked" by applying `k` consecutive random transitions, and the inner
+/// search is restarted from the perturbed point. The global best is always kept; a new local optimum is adopted as the
+/// next restart anchor according to the acceptance rule.
+#[derive(Clone, Copy)]
+pub struct IteratedLocalSearchOptimizer<O, P> {
+    inner: O,
+    k: usize,
+    n_restarts: usize,
+    accept: P,
+}
+
+impl<O, P> IteratedLocalSearchOptimizer<O, P> {
+    /// Constructor of IteratedLocalSearchOptimizer
+    ///
+    /// - `inner` : the inner optimizer run to a local optimum at each restart
+    /// - `k` : number of consecutive random transitions applied as a perturbation kick
+    /// - `n_restarts` : number of perturbation/restart rounds
+    /// - `accept` : acceptance rule for the restart anchor. It reuses the
+    ///   [`TransitionProbabilityFn`] logic, so passing `|current, trial| if trial < current { 1.0 } else { 0.0 }`
+    ///   yields plain "accept only if it improves".
+    pub fn new(inner: O, k: usize, n_restarts: usize, accept: P) -> Self {
+        Self {
+            inner,
+            k,
+            n_restarts,
+            accept,
+        }
+    }
+}
+
+impl<M, O, P> LocalSearchOptimizer<M> for IteratedLocalSearchOptimizer<O, P>
+where
+    M: OptModel,
+    O: LocalSearchOptimizer<M>,
+    P: TransitionProbabilityFn<M::ScoreType>,
+{
+    /// Start optimization
+    ///
+    /// - `model` : the model to optimize
+    /// - `initial_solution` : the initial solution to start optimization
+    /// - `initial_score` : the score of the initial solution
+    /// - `n_iter`: maximum iterations of each inner search
+    /// - `time_limit` : the optimizer will stop after this duration
+    /// - `callback` : callback function forwarded to the inner optimizer
+    fn optimize<F>(
+        &self,
+        model: &M,
+        initial_solution: M::SolutionType,
+        initial_score: M::ScoreType,
+        n_iter: usize,
+        time_limit: Duration,
+        callback: Option<&F>,
+    ) -> (M::SolutionType, M::ScoreType)
+    where
+        M: OptModel,
+        F: OptCallbackFn<M::SolutionType, M::ScoreType>,
+    {
+        let mut rng = rand::thread_rng();
+        let start_time = Instant::now();
+
+        // split the budget across the initial search and every restart, so a
+        // time-bounded inner optimizer does not spend the whole limit on the
+        // first call and starve the perturbation kicks.
+        let slice = time_limit / (self.n_restarts as u32 + 1);
+
+        let (mut anchor_solution, mut anchor_score) = self.inner.optimize(
+            model,
+            initial_solution,
+            initial_score,
+            n_iter,
+            slice,
+            callback,
+        );
+
+        let best_solution = Rc::new(RefCell::new(anchor_solution.clone()));
+        let mut best_score = anchor_score;
+
+        for _ in 0..self.n_restarts {
+            let elapsed = start_time.elapsed();
+            if elapsed >= time_limit {
+                break;
+            }
+
+            // kick : apply k consecutive random transitions to the anchor
+            let mut perturbed_solution = anchor_solution.clone();
+            let mut perturbed_score = anchor_score;
+            for _ in 0..self.k {
+                let (solution, _, score) = model.generate_trial_state(
+                    &perturbed_solution,
+                    &mut rng,
+                    Some(perturbed_score),
+                );
+                perturbed_solution = solution;
+                perturbed_score = score;
+            }
+
+            let remaining = time_limit.saturating_sub(elapsed);
+            let (candidate_solution, candidate_score) = self.inner.optimize(
+                model,
+                perturbed_solution,
+                perturbed_score,
+                n_iter,
+                slice.min(remaining),
+                callback,
+            );
+
+            if candidate_score < best_score {
+                best_solution.replace(candidate_solution.clone());
+                best_score = candidate_score;
+            }
+
+            // decide whether the new local optimum becomes the next anchor
+            let p = (self.accept)(anchor_score, candidate_score);
+            let r: f64 = rng.gen();
+            if p > r {
+                anchor_solution = candidate_solution;
+                anchor_score = candidate_score;
+            }
+        }
+
+        let best_solution = (*best_solution.borrow()).clone();
+        (best_solution, best_score)
+    }
+}
@@ -0,0 +1,249 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Instant;
+
+use ordered_float::NotNan;
+use rand::Rng;
+
+use crate::{callback::OptCallbackFn, Duration, OptModel};
+
+use super::incremental::IncrementalOptModel;
+use super::{callback::OptProgress, LocalSearchOptimizer};
+
+/// How the temperature is lowered over the course of the optimization.
+#[derive(Clone, Copy, Debug)]
+pub enum CoolingSchedule {
+    /// Temperature follows the fraction of iterations consumed (`it / n_iter`).
+    Iteration,
+    /// Temperature follows the fraction of the time budget elapsed
+    /// (`t_elapsed / time_limit`), so cooling is independent of per-iteration cost.
+    WallClock,
+}
+
+/// Optimizer that implements the simulated annealing algorithm
+///
+/// On each iteration the temperature `T` is interpolated geometrically between
+/// `start_temp` and `end_temp` following the [`CoolingSchedule`], and a trial
+/// solution is accepted with probability `exp(-(trial - current) / T)`.
+#[derive(Clone, Copy)]
+pub struct SimulatedAnnealingOptimizer {
+    patience: usize,
+    n_trials: usize,
+    start_temp: f64,
+    end_temp: f64,
+    cooling: CoolingSchedule,
+}
+
+impl SimulatedAnnealingOptimizer {
+    /// Constructor of SimulatedAnnealingOptimizer cooling on the iteration count.
+    ///
+    /// - `patience` : the optimizer will give up
+    ///   if there is no improvement of the score after this number of iterations
+    /// - `n_trials` : number of trial solutions to generate and evaluate at each iteration
+    /// - `start_temp` : temperature at the first iteration
+    /// - `end_temp` : temperature at the last iteration
+    pub fn new(patience: usize, n_trials: usize, start_temp: f64, end_temp: f64) -> Self {
+        Self::with_cooling_schedule(
+            patience,
+            n_trials,
+            start_temp,
+            end_temp,
+            CoolingSchedule::Iteration,
+        )
+    }
+
+    /// Constructor of SimulatedAnnealingOptimizer with an explicit cooling schedule.
+    ///
+    /// - `patience` : the optimizer will give up
+    ///   if there is no improvement of the score after this number of iterations
+    /// - `n_trials` : number of trial solutions to generate and evaluate at each iteration
+    /// - `start_temp` : temperature at the start of the schedule
+    /// - `end_temp` : temperature at the end of the schedule
+    /// - `cooling` : how the temperature is lowered over time
+    pub fn with_cooling_schedule(
+        patience: usize,
+        n_trials: usize,
+        start_temp: f64,
+        end_temp: f64,
+        cooling: CoolingSchedule,
+    ) -> Self {
+        Self {
+            patience,
+            n_trials,
+            start_temp,
+            end_temp,
+            cooling,
+        }
+    }
+}
+
+impl<M: OptModel<ScoreType = NotNan<f64>>> LocalSearchOptimizer<M>
+    for SimulatedAnnealingOptimizer
+{
+    /// Start optimization
+    ///
+    /// - `model` : the model to optimize
+    /// - `initial_solution` : the initial solution to start optimization
+    /// - `initial_score` : the score of the initial solution
+    /// - `n_iter`: maximum iterations
+    /// - `time_limit` : the optimizer will stop after this duration
+    /// - `callback` : callback function that will be invoked at the end of each iteration
+    fn optimize<F>(
+        &self,
+        model: &M,
+        initial_solution: M::SolutionType,
+        initial_score: M::ScoreType,
+        n_iter: usize,
+        time_limit: Duration,
+        callback: Option<&F>,
+    ) -> (M::SolutionType, M::ScoreType)
+    where
+        M: OptModel,
+        F: OptCallbackFn<M::SolutionType, M::ScoreType>,
+    {
+        let mut rng = rand::thread_rng();
+        let mut current_solution = initial_solution;
+        let mut current_score = initial_score;
+        let best_solution = Rc::new(RefCell::new(current_solution.clone()));
+        let mut best_score = current_score;
+        let mut counter = 0;
+        let start_time = Instant::now();
+
+        for it in 0..n_iter {
+            let elapsed = start_time.elapsed();
+            if elapsed > time_limit {
+                break;
+            }
+
+            let fraction = match self.cooling {
+                CoolingSchedule::Iteration => it as f64 / n_iter as f64,
+                // a zero time limit would make the ratio NaN/inf; fall back to
+                // the iteration fraction so the temperature stays finite.
+                CoolingSchedule::WallClock if time_limit.is_zero() => it as f64 / n_iter as f64,
+                CoolingSchedule::WallClock => elapsed.as_secs_f64() / time_limit.as_secs_f64(),
+            };
+            let temp = self.start_temp * (self.end_temp / self.start_temp).powf(fraction);
+
+            let (trial_solution, _, trial_score) = (0..self.n_trials)
+                .map(|_| {
+                    let mut rng = rand::thread_rng();
+                    model.generate_trial_state(&current_solution, &mut rng, Some(current_score))
+                })
+                .min_by_key(|(_, _, score)| *score)
+                .unwrap();
+
+            let delta = (trial_score - current_score).into_inner();
+            let p = (-delta / temp).exp();
+            let r: f64 = rng.gen();
+
+            if p > r {
+                current_solution = trial_solution;
+                current_score = trial_score;
+            }
+
+            if current_score < best_score {
+                best_solution.replace(current_solution.clone());
+                best_score = current_score;
+                counter = 0;
+            } else {
+                counter += 1;
+                if counter >= self.patience {
+                    break;
+                }
+            }
+
+            if let Some(f) = callback {
+                let progress = OptProgress::new(it, counter, best_solution.clone(), best_score);
+                f(progress);
+            }
+        }
+
+        let best_solution = (*best_solution.borrow()).clone();
+        (best_solution, best_score)
+    }
+}
+
+impl SimulatedAnnealingOptimizer {
+    /// Start optimization using the incremental [`IncrementalOptModel`] path.
+    ///
+    /// Proposes transitions with `generate_transition` (no trial state is built),
+    /// scores them with `evaluate_delta`, and mutates a single owned state in
+    /// place: the best transition is applied, accepted with probability
+    /// `exp(-(trial - current) / T)`, and rolled back with `rollback_move`
+    /// otherwise. The temperature follows the iteration-count schedule; the state
+    /// is cloned only when a new global best is recorded.
+    ///
+    /// - `model` : the model to optimize
+    /// - `initial_state` : the initial state to start optimization. If None, a random state will be generated.
+    /// - `n_iter`: maximum iterations
+    /// - `callback` : callback function that will be invoked at the end of each iteration
+    pub fn optimize_incremental<M, F>(
+        &self,
+        model: &M,
+        initial_state: Option<M::StateType>,
+        n_iter: usize,
+        callback: Option<&F>,
+    ) -> (M::StateType, M::ScoreType)
+    where
+        M: IncrementalOptModel<ScoreType = NotNan<f64>>,
+        F: OptCallbackFn<M::StateType, M::ScoreType>,
+    {
+        let mut rng = rand::thread_rng();
+        let mut current_state = if let Some(s) = initial_state {
+            s
+        } else {
+            model.generate_random_state(&mut rng).unwrap()
+        };
+        let mut current_score = model.evaluate_state(&current_state);
+        let best_state = Rc::new(RefCell::new(current_state.clone()));
+        let mut best_score = current_score;
+        let mut counter = 0;
+        for it in 0..n_iter {
+            let fraction = it as f64 / n_iter as f64;
+            let temp = self.start_temp * (self.end_temp / self.start_temp).powf(fraction);
+
+            // pick the best transition by delta; the state is not mutated yet, so
+            // every delta is measured against the same current state.
+            let (transition, trial_score) = (0..self.n_trials)
+                .map(|_| {
+                    let transition = model.generate_transition(&current_state, &mut rng);
+                    let trial_score =
+                        current_score + model.evaluate_delta(&current_state, &transition);
+                    (transition, trial_score)
+                })
+                .min_by_key(|(_, score)| *score)
+                .unwrap();
+
+            let delta = (trial_score - current_score).into_inner();
+            let p = (-delta / temp).exp();
+            let r: f64 = rng.gen();
+
+            // apply in place, then keep or roll the move back.
+            model.apply_move(&mut current_state, &transition);
+            if p > r {
+                current_score = trial_score;
+            } else {
+                model.rollback_move(&mut current_state, &transition);
+            }
+
+            if current_score < best_score {
+                best_state.replace(current_state.clone());
+                best_score = current_score;
+                counter = 0;
+            } else {
+                counter += 1;
+                if counter >= self.patience {
+                    break;
+                }
+            }
+
+            if let Some(f) = callback {
+                let progress = OptProgress::new(it, counter, best_state.clone(), best_score);
+                f(progress);
+            }
+        }
+
+        let best_state = (*best_state.borrow()).clone();
+        (best_state, best_score)
+    }
+}
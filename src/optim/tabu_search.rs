@@ -0,0 +1,188 @@
+use std::collections::VecDeque;
+use std::time::Instant;
+use std::{cell::RefCell, rc::Rc};
+
+use rayon::prelude::*;
+
+use crate::{Duration, OptModel};
+
+use super::callback::{OptCallbackFn, OptProgress};
+
+/// Tabu list that remembers recently accepted transitions so that the
+/// optimizer does not immediately undo them and fall into a cycle.
+///
+/// Users implement this trait for their problem. `contains` reports whether a
+/// transition is currently forbidden and `append` records the most recently
+/// accepted transition, evicting the oldest entry once the list is full.
+pub trait TabuList: Default {
+    /// Type of the transition stored in the list.
+    /// This is exactly the middle element returned by [`OptModel::generate_trial_state`].
+    type Item;
+
+    /// Returns `true` if `item` is currently tabu.
+    fn contains(&self, item: &Self::Item) -> bool;
+
+    /// Registers `item` as the most recent tabu move, dropping the oldest one
+    /// when the list has reached its capacity.
+    fn append(&mut self, item: Self::Item);
+}
+
+/// Bounded FIFO [`TabuList`] backed by a ring buffer.
+#[derive(Clone, Debug)]
+pub struct DequeTabuList<T> {
+    buff: VecDeque<T>,
+    max_size: usize,
+}
+
+impl<T> DequeTabuList<T> {
+    /// Constructor of DequeTabuList
+    ///
+    /// - `max_size` : maximum number of transitions to remember
+    pub fn new(max_size: usize) -> Self {
+        Self {
+            buff: VecDeque::with_capacity(max_size),
+            max_size,
+        }
+    }
+}
+
+impl<T> Default for DequeTabuList<T> {
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+impl<T: PartialEq> TabuList for DequeTabuList<T> {
+    type Item = T;
+
+    fn contains(&self, item: &Self::Item) -> bool {
+        self.buff.contains(item)
+    }
+
+    fn append(&mut self, item: Self::Item) {
+        if self.buff.len() == self.max_size {
+            self.buff.pop_front();
+        }
+        self.buff.push_back(item);
+    }
+}
+
+/// Optimizer that implements the tabu search algorithm
+///
+/// At each iteration `n_trials` neighbors are generated in parallel and sorted
+/// by score. The best neighbor whose transition is not tabu is accepted; an
+/// aspiration criterion overrides the tabu status whenever a move improves on
+/// the global best. Accepted transitions are pushed onto the tabu list.
+#[derive(Clone, Copy)]
+pub struct TabuSearchOptimizer {
+    patience: usize,
+    n_trials: usize,
+}
+
+impl TabuSearchOptimizer {
+    /// Constructor of TabuSearchOptimizer
+    ///
+    /// - `patience` : the optimizer will give up
+    ///   if there is no improvement of the score after this number of iterations
+    /// - `n_trials` : number of trial solutions to generate and evaluate at each iteration
+    pub fn new(patience: usize, n_trials: usize) -> Self {
+        Self { patience, n_trials }
+    }
+
+    /// Start optimization
+    ///
+    /// - `model` : the model to optimize
+    /// - `initial_state` : the initial state to start optimization. If None, a random state will be generated.
+    /// - `n_iter`: maximum iterations
+    /// - `time_limit` : the optimizer will stop after this duration
+    /// - `tabu_list` : the tabu list that records accepted transitions
+    /// - `callback` : callback function that will be invoked at the end of each iteration
+    pub fn optimize<M, F, L>(
+        &self,
+        model: &M,
+        initial_state: Option<M::StateType>,
+        n_iter: usize,
+        time_limit: Duration,
+        mut tabu_list: L,
+        callback: Option<&F>,
+    ) -> (M::StateType, M::ScoreType)
+    where
+        M: OptModel + Sync + Send,
+        F: OptCallbackFn<M::StateType, M::ScoreType>,
+        L: TabuList<Item = M::TransitionType>,
+    {
+        let mut rng = rand::thread_rng();
+        let mut current_state = if let Some(s) = initial_state {
+            s
+        } else {
+            model.generate_random_state(&mut rng).unwrap()
+        };
+        let mut current_score = model.evaluate_state(&current_state);
+        let best_state = Rc::new(RefCell::new(current_state.clone()));
+        let mut best_score = current_score;
+        let mut counter = 0;
+        let mut accepted_counter = 0;
+        let start_time = Instant::now();
+        for it in 0..n_iter {
+            if start_time.elapsed() > time_limit {
+                break;
+            }
+
+            let mut trials = (0..self.n_trials)
+                .into_par_iter()
+                .map(|_| {
+                    let mut rng = rand::thread_rng();
+                    model.generate_trial_state(&current_state, &mut rng, Some(current_score))
+                })
+                .collect::<Vec<_>>();
+            trials.sort_unstable_by_key(|(_, _, score)| *score);
+
+            // accept the best neighbor whose transition is not tabu, unless the
+            // aspiration criterion (beating the global best) overrides it.
+            let selected = trials
+                .into_iter()
+                .find(|(_, transition, score)| !tabu_list.contains(transition) || *score < best_score);
+
+            let (trial_state, trial_transition, trial_score) = match selected {
+                Some(t) => t,
+                None => {
+                    counter += 1;
+                    if counter >= self.patience {
+                        break;
+                    }
+                    if let Some(f) = callback {
+                        let progress =
+                            OptProgress::new(it, accepted_counter, best_state.clone(), best_score);
+                        f(progress);
+                    }
+                    continue;
+                }
+            };
+
+            tabu_list.append(trial_transition);
+            current_state = trial_state;
+            current_score = trial_score;
+            accepted_counter += 1;
+
+            if current_score < best_score {
+                best_state.replace(current_state.clone());
+                best_score = current_score;
+                counter = 0;
+            } else {
+                counter += 1;
+                if counter >= self.patience {
+                    break;
+                }
+            }
+
+            if let Some(f) = callback {
+                let progress =
+                    OptProgress::new(it, accepted_counter, best_state.clone(), best_score);
+                f(progress);
+            }
+        }
+
+        let best_state = (*best_state.borrow()).clone();
+        (best_state, best_score)
+    }
+}
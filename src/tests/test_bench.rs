@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use crate::bench::{Adapter, DynLocalSearchOptimizer, StudyRunner};
+use crate::optim::{HillClimbingOptimizer, SimulatedAnnealingOptimizer};
+
+use super::QuadraticModel;
+
+#[test]
+fn test() {
+    let model = QuadraticModel::new(3, vec![2.0, 0.0, -3.5], (-10.0, 10.0));
+    // two distinct optimizer families: SA via the base trait, hill climbing via the adapter
+    let optimizers: Vec<(String, Box<dyn DynLocalSearchOptimizer<_> + Sync + Send>)> = vec![
+        (
+            "sa".to_string(),
+            Box::new(SimulatedAnnealingOptimizer::new(10000, 10, 1.0, 0.1)),
+        ),
+        (
+            "hc".to_string(),
+            Box::new(Adapter(HillClimbingOptimizer::new(1000, 10))),
+        ),
+    ];
+    let runner = StudyRunner::new(
+        optimizers,
+        &model,
+        vec![0, 1, 2],
+        5000,
+        Duration::from_secs(10),
+        2,
+        Some(0.05),
+    );
+    let records = runner.run().unwrap();
+    assert_eq!(records.len(), 2);
+    assert!(records.iter().all(|r| r.runs.len() == 3));
+    assert!(records.iter().all(|r| r.best_score < 0.05));
+    assert!(records
+        .iter()
+        .all(|r| r.runs.iter().all(|run| !run.score_curve.is_empty())));
+}
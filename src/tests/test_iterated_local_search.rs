@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use approx::assert_abs_diff_eq;
+use ordered_float::NotNan;
+
+use crate::optim::{
+    IteratedLocalSearchOptimizer, LocalSearchOptimizer, SimulatedAnnealingOptimizer,
+};
+
+use super::QuadraticModel;
+
+#[test]
+fn test() {
+    let model = QuadraticModel::new(3, vec![2.0, 0.0, -3.5], (-10.0, 10.0));
+    let inner = SimulatedAnnealingOptimizer::new(1000, 10, 1.0, 0.1);
+    // accept a new local optimum as the anchor only if it improves
+    let accept = |current: NotNan<f64>, trial: NotNan<f64>| if trial < current { 1.0 } else { 0.0 };
+    let opt = IteratedLocalSearchOptimizer::new(inner, 3, 20, accept);
+    let null_closure = None::<&fn(_)>;
+    let (final_solution, final_score) = opt
+        .run(&model, None, 1000, Duration::from_secs(10), null_closure)
+        .unwrap();
+    assert_abs_diff_eq!(2.0, final_solution[0], epsilon = 0.05);
+    assert_abs_diff_eq!(0.0, final_solution[1], epsilon = 0.05);
+    assert_abs_diff_eq!(-3.5, final_solution[2], epsilon = 0.05);
+    assert_abs_diff_eq!(0.0, final_score.into_inner(), epsilon = 0.05);
+}